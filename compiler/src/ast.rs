@@ -1,5 +1,4 @@
-use crate::{ParseResult, Node, Token, TokenType, Classification};
-use expr_lang_common::Result;
+use crate::{ParseResult, Node, Span, SyntaxError, Token, TokenType, Classification};
 
 pub struct AST {
     pub(crate) nodes: Vec<Node>,
@@ -7,127 +6,362 @@ pub struct AST {
     pub(crate) sub_roots: Vec<usize>
 }
 
+impl AST {
+    /// The root node index of each parenthesized sub-expression, in source
+    /// order - e.g. for `"(1 + 2) * (3 + 4)"` this is the two `+` roots,
+    /// letting a caller walk or re-evaluate a group independently of the
+    /// expression that encloses it.
+    pub fn sub_roots(&self) -> &[usize] {
+        &self.sub_roots
+    }
+}
+
 #[derive(PartialEq, Copy, Clone)]
 enum OpType {
     Binary,
+    BinaryRight,
     UnaryLeft,
-    UnaryRight,
+    // Claims whichever of left/right is present, same as Binary, but never
+    // errors when one or both are absent - an iteration control keyword is
+    // valid on its own (no enclosing iteration) as well as wired between a
+    // preceding body expression and a following argument.
+    BinaryOptional,
 }
 
-pub fn make_ast(mut parse_result: ParseResult) -> Result<AST> {
+pub fn make_ast(mut parse_result: ParseResult) -> Result<AST, SyntaxError> {
     if parse_result.nodes.is_empty() {
         return Ok(AST {
             nodes: vec![Node {
                 classification: Classification::Literal,
                 token: Token {
                     value: String::from(""),
-                    token_type: TokenType::UnitLiteral
+                    token_type: TokenType::UnitLiteral,
+                    span: Span { start: 0, end: 0 }
                 },
+                span: Span { start: 0, end: 0 },
                 left: None,
                 right: None,
-                parent: None
+                parent: None,
+                children: vec![]
             }],
             root: 0,
             sub_roots: vec![]
         });
     }
 
+    let node_count = parse_result.nodes.len();
+    let mut excluded = vec![false; node_count];
+
+    // Resolve parenthesized groups innermost-first (deepest start index,
+    // shortest range first among ties) so a group is always fully resolved
+    // before the group that encloses it tries to splice it into place.
+    let mut groups = parse_result.sub_expressions.clone();
+    groups.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let mut group_roots: Vec<(usize, usize)> = vec![]; // (group start, resolved root), for sub_roots ordering
+
+    for (start, end) in groups.iter() {
+        let indices: Vec<usize> = (*start..=*end).filter(|i| !excluded[*i]).collect();
+        link_precedence(&mut parse_result.nodes, &indices)?;
+
+        let root_index = find_root(&parse_result.nodes, &indices)?;
+        group_roots.push((*start, root_index));
+
+        excluded[*start..=*end].fill(true);
+
+        // Splice the group's root into whatever sits on either side of it
+        // in the enclosing expression, so that expression sees the group as
+        // a single already-resolved value rather than the range it spanned.
+        let before = if *start > 0 { Some(start - 1) } else { None };
+        let after = if end + 1 < node_count { Some(end + 1) } else { None };
+
+        if let Some(b) = before {
+            parse_result.nodes[b].right = Some(root_index);
+        }
+
+        // both neighbors point at the group's root; whichever of them binds
+        // tighter claims it first and `link_precedence` relinks the other
+        // to that operator
+        if let Some(a) = after {
+            parse_result.nodes[a].left = Some(root_index);
+        }
+    }
+
+    let top_level: Vec<usize> = (0..node_count).filter(|i| !excluded[*i]).collect();
+
+    // an expression that is itself a single, fully-enclosing group (e.g.
+    // "(1 + 2)") leaves nothing outside of it; its own root is the AST root
+    let root = if top_level.is_empty() {
+        group_roots.last()
+            .map(|(_, root)| *root)
+            .ok_or(SyntaxError::UnresolvedExpression { span: Span { start: 0, end: 0 } })?
+    } else {
+        link_precedence(&mut parse_result.nodes, &top_level)?;
+        find_root(&parse_result.nodes, &top_level)?
+    };
+
+    group_roots.sort_by_key(|(start, _)| *start);
+    let sub_roots = group_roots.into_iter().map(|(_, root)| root).collect();
+
+    Ok(AST {
+        nodes: parse_result.nodes,
+        root,
+        sub_roots
+    })
+}
+
+/// Finds the single node among `indices` that nothing else in this pass
+/// claimed as an operand. Returns `Err(SyntaxError::UnresolvedExpression)`
+/// if every node ended up claimed - e.g. two adjacent `BinaryOptional`
+/// operators (iteration control keywords) claiming each other, leaving no
+/// root behind.
+fn find_root(nodes: &[Node], indices: &[usize]) -> Result<usize, SyntaxError> {
+    match indices.iter().cloned().find(|i| nodes[*i].parent.is_none()) {
+        Some(root) => Ok(root),
+        None => {
+            let start = indices.first().map(|i| nodes[*i].span.start).unwrap_or(0);
+            let end = indices.last().map(|i| nodes[*i].span.end).unwrap_or(0);
+
+            Err(SyntaxError::UnresolvedExpression { span: Span { start, end } })
+        }
+    }
+}
+
+/// Runs one tightest-to-loosest precedence sweep over `eligible`, linking
+/// each operator to its operands. `eligible` is restricted to a single
+/// group's node range (or, for the top level, every node not already
+/// claimed by a group), so operators never bind across a group boundary.
+/// Returns `Err(SyntaxError::DanglingOperand)` for an operator that is
+/// missing an operand it requires once its turn to link comes up.
+fn link_precedence(nodes: &mut [Node], eligible: &[usize]) -> Result<(), SyntaxError> {
+    // Tightest to loosest binding: decimal/access, unary, exponent,
+    // multiplicative, additive, relational, equality, logical-and,
+    // logical-or, iteration control keywords, iteration.
     let mut op_locations: Vec<(OpType, Vec<usize>)> = vec![
-        (OpType::Binary, vec![]),
-        (OpType::Binary, vec![]),
-        (OpType::UnaryLeft, vec![]),
+        (OpType::Binary, vec![]),         // 0: decimal
+        (OpType::Binary, vec![]),         // 1: access
+        (OpType::UnaryLeft, vec![]),      // 2: negation / absolute value / not
+        (OpType::BinaryRight, vec![]),    // 3: exponent
+        (OpType::Binary, vec![]),         // 4: multiplicative
+        (OpType::Binary, vec![]),         // 5: additive
+        (OpType::Binary, vec![]),         // 6: relational
+        (OpType::Binary, vec![]),         // 7: equality
+        (OpType::Binary, vec![]),         // 8: logical and
+        (OpType::Binary, vec![]),         // 9: logical or
+        (OpType::BinaryOptional, vec![]), // 10: iteration output / skip / continue / complete
+        (OpType::Binary, vec![]),         // 11: iteration
     ];
 
-    for (i, node) in parse_result.nodes.iter().enumerate() {
-        let p = match node.classification {
-            Classification::Literal 
-            | Classification::IterationOutput 
-            | Classification::IterationSkip 
-            | Classification::IterationContinue
-            | Classification::IterationComplete => continue,
+    for i in eligible.iter().cloned() {
+        let p = match nodes[i].classification {
+            Classification::Literal => continue,
             Classification::Decimal => 0,
             Classification::Access => 1,
             Classification::Negation
-            | Classification::AbsoluteValue 
+            | Classification::AbsoluteValue
             | Classification::Not => 2,
-            _ => unimplemented!()
+            Classification::Exponent => 3,
+            Classification::Multiplication
+            | Classification::Division
+            | Classification::Modulus => 4,
+            Classification::Addition
+            | Classification::Subtraction => 5,
+            Classification::LessThan
+            | Classification::LessThanOrEqual
+            | Classification::GreaterThan
+            | Classification::GreaterThanOrEqual => 6,
+            Classification::Equality
+            | Classification::Inequality => 7,
+            Classification::And => 8,
+            Classification::Or => 9,
+            Classification::IterationOutput
+            | Classification::IterationSkip
+            | Classification::IterationContinue
+            | Classification::IterationComplete => 10,
+            Classification::Iteration => 11,
         };
 
         op_locations[p].1.push(i);
     }
 
     for precedence in op_locations.iter() {
-        for loc in precedence.1.iter() {
+        let locs: Vec<usize> = if precedence.0 == OpType::BinaryRight {
+            precedence.1.iter().rev().cloned().collect()
+        } else {
+            precedence.1.clone()
+        };
+
+        for loc in locs.iter() {
             // get op's left and right
             // update parent to be loc
             // if value set left and right to None
 
-            let (left, right) = parse_result.nodes.get(*loc).map(|n| (n.left, n.right)).unwrap();
+            let (left, right) = nodes.get(*loc).map(|n| (n.left, n.right)).unwrap();
 
-            if precedence.0 != OpType::UnaryLeft {
+            if precedence.0 != OpType::BinaryOptional
+                && (right.is_none() || (precedence.0 != OpType::UnaryLeft && left.is_none())) {
+                return Err(SyntaxError::DanglingOperand {
+                    span: nodes[*loc].span,
+                    classification: nodes[*loc].classification
+                });
+            }
+
+            // a non-literal operand is itself an already-resolved subtree
+            // (e.g. from a tighter precedence level, an already-spliced
+            // group, or an earlier op in this same left/right-associative
+            // sweep); its own left/right are its children, not chain
+            // neighbors, so for it the neighbor to relink is the unclaimed
+            // node whose chain pointer still points at that subtree
+            let new_left = if precedence.0 != OpType::UnaryLeft {
                 match left {
                     Some(i) => {
-                        parse_result.nodes[i].parent = Some(*loc);
+                        let is_literal = nodes[i].classification == Classification::Literal;
+                        let l = if is_literal { nodes[i].left } else {
+                            eligible.iter().cloned().find(|n| nodes[*n].parent.is_none() && nodes[*n].right == Some(i))
+                        };
+
+                        nodes[i].parent = Some(*loc);
 
-                        if parse_result.nodes[i].classification == Classification::Literal {
-                            parse_result.nodes[i].left = None;
-                            parse_result.nodes[i].right = None;
+                        if is_literal {
+                            nodes[i].left = None;
+                            nodes[i].right = None;
                         }
+
+                        l
                     }
-                    None => () // nothing to do
+                    None => None // nothing to do
                 }
+            } else {
+                None
+            };
+
+            // update this left node's right to point to this node, so a
+            // looser-binding op further left in the chain sees this op (and
+            // its now-claimed operands) rather than the operand it consumed
+            if let Some(l) = new_left {
+                nodes[l].right = Some(*loc);
             }
 
             let new_right = match right {
                 Some(i) => {
-                    let r = parse_result.nodes[i].right;
-                    parse_result.nodes[i].parent = Some(*loc);
+                    let is_literal = nodes[i].classification == Classification::Literal;
+                    let r = if is_literal { nodes[i].right } else {
+                        eligible.iter().cloned().find(|n| nodes[*n].parent.is_none() && nodes[*n].left == Some(i))
+                    };
 
-                    if parse_result.nodes[i].classification == Classification::Literal {
-                        parse_result.nodes[i].left = None;
-                        parse_result.nodes[i].right = None;
+                    nodes[i].parent = Some(*loc);
+
+                    if is_literal {
+                        nodes[i].left = None;
+                        nodes[i].right = None;
                     }
 
                     r
                 }
                 None => None // nothing to do
             };
-            
+
             // update this right node's left to point to this node
-            match new_right {
-                Some(r) => {
-                    parse_result.nodes[r].left = Some(*loc);
-                }
-                None => () // nothing to update
+            if let Some(r) = new_right {
+                nodes[r].left = Some(*loc);
             }
         }
+
+        fold_sequences(nodes, &locs)?;
+    }
+
+    Ok(())
+}
+
+/// Addition, multiplication, and `&&`/`||` give the same result for any
+/// grouping of a run of the same operator, so a chain of them doesn't need
+/// the nested `left`/`right` pairs the binary sweep above just built -
+/// evaluators can fold over a flat operand list instead.
+fn is_sequence_classification(classification: Classification) -> bool {
+    matches!(classification, Classification::Addition
+        | Classification::Multiplication
+        | Classification::And
+        | Classification::Or)
+}
+
+/// Collapses every maximal run of two or more identically classified nodes
+/// in `locs` that are *actually chain-linked* (as just built into a
+/// left-leaning `((a op b) op c) op d` chain by this precedence level's
+/// binary sweep, i.e. each next op's `left` points at the previous op) into
+/// the run's outermost node, replacing its `left`/`right` with a
+/// source-ordered `children` list. Same-classification ops that merely sit
+/// next to each other in `locs` without that linkage - e.g. the two `*` in
+/// `2 * 3 + 4 * 5`, separated by a not-yet-processed `+` - are left alone,
+/// as is a lone operator (a run of one).
+fn fold_sequences(nodes: &mut [Node], locs: &[usize]) -> Result<(), SyntaxError> {
+    let mut start = 0;
+
+    while start < locs.len() {
+        let classification = nodes[locs[start]].classification;
+
+        let mut end = start;
+        while end + 1 < locs.len()
+            && nodes[locs[end + 1]].classification == classification
+            && nodes[locs[end + 1]].left == Some(locs[end])
+        {
+            end += 1;
+        }
+
+        if is_sequence_classification(classification) && end > start {
+            flatten_chain(nodes, &locs[start..=end])?;
+        }
+
+        start = end + 1;
     }
 
-    let mut root_index = *parse_result.sub_expressions.get(0).unwrap(); // should always have 1
-    let mut node = &parse_result.nodes[root_index];
+    Ok(())
+}
+
+/// Every node in `run` is a `Binary`-tier operator that already passed the
+/// dangling-operand check in `link_precedence`'s per-op loop, so its
+/// `left`/`right` should always be present here; these `ok_or_else`s exist
+/// to turn a would-be panic into a descriptive error should that invariant
+/// ever not hold, rather than asserting it can't.
+fn flatten_chain(nodes: &mut [Node], run: &[usize]) -> Result<(), SyntaxError> {
+    let outer = *run.last().ok_or(SyntaxError::UnresolvedExpression { span: Span { start: 0, end: 0 } })?;
+
+    let missing_operand = |loc: usize| SyntaxError::DanglingOperand {
+        span: nodes[loc].span,
+        classification: nodes[loc].classification,
+    };
+
+    let mut children = vec![nodes[run[0]].left.ok_or_else(|| missing_operand(run[0]))?];
+    for loc in run.iter() {
+        children.push(nodes[*loc].right.ok_or_else(|| missing_operand(*loc))?);
+    }
+
+    for operand in children.iter() {
+        nodes[*operand].parent = Some(outer);
+    }
 
-    while node.parent.is_some() {
-        root_index = node.parent.unwrap();
-        node = &parse_result.nodes[root_index];
+    for loc in &run[..run.len() - 1] {
+        nodes[*loc].parent = Some(outer);
+        nodes[*loc].left = None;
+        nodes[*loc].right = None;
     }
 
-    return Ok(AST {
-        nodes: parse_result.nodes.clone(),
-        root: root_index,
-        sub_roots: vec![]
-    });
+    nodes[outer].left = None;
+    nodes[outer].right = None;
+    nodes[outer].children = children;
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{make_ast, AST, Lexer, TokenType, Token, Node, Parser, Classification};
+    use crate::{make_ast, AST, Lexer, Span, TokenType, Token, Node, Parser, Classification};
 
     pub fn ast_from(s: &str) -> AST {
         let input = Lexer::new().lex(s).unwrap();
         let parser = Parser::new();
         let parse_result = parser.make_groups(&input).unwrap();
-        
-        return make_ast(parse_result).unwrap();
+
+        make_ast(parse_result).unwrap()
     }
 
     #[test]
@@ -139,10 +373,13 @@ mod tests {
             token: Token {
                 value: String::from(""),
                 token_type: TokenType::UnitLiteral,
+                span: Span { start: 0, end: 0 },
             },
+            span: Span { start: 0, end: 0 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -156,9 +393,9 @@ mod tests {
 
 #[cfg(test)]
 mod value_precedence_tests {
-    use crate::{Lexer, TokenType, Token, Node, Parser, Classification};
+    use crate::{Span, TokenType, Token, Node, Classification};
     use super::tests::ast_from;
-    
+
     #[test]
     fn number_only() {
         let ast = ast_from("10");
@@ -168,10 +405,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("10"),
                 token_type: TokenType::Number,
+                span: Span { start: 0, end: 2 },
             },
+            span: Span { start: 0, end: 2 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -186,10 +426,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("a"),
                 token_type: TokenType::Character,
+                span: Span { start: 0, end: 3 },
             },
+            span: Span { start: 0, end: 3 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -204,10 +447,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("hello world"),
                 token_type: TokenType::CharacterList,
+                span: Span { start: 0, end: 13 },
             },
+            span: Span { start: 0, end: 13 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -222,10 +468,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("my_value"),
                 token_type: TokenType::Identifier,
+                span: Span { start: 0, end: 8 },
             },
+            span: Span { start: 0, end: 8 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -240,10 +489,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from(":"),
                 token_type: TokenType::SymbolOperator,
+                span: Span { start: 0, end: 1 },
             },
+            span: Span { start: 0, end: 1 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -258,10 +510,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("()"),
                 token_type: TokenType::UnitLiteral,
+                span: Span { start: 0, end: 2 },
             },
+            span: Span { start: 0, end: 2 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -276,10 +531,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("$"),
                 token_type: TokenType::Input,
+                span: Span { start: 0, end: 1 },
             },
+            span: Span { start: 0, end: 1 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -294,10 +552,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("?"),
                 token_type: TokenType::Result,
+                span: Span { start: 0, end: 1 },
             },
+            span: Span { start: 0, end: 1 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -312,10 +573,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("|>output"),
                 token_type: TokenType::IterationOutput,
+                span: Span { start: 0, end: 8 },
             },
+            span: Span { start: 0, end: 8 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -330,10 +594,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("|>skip"),
                 token_type: TokenType::IterationSkip,
+                span: Span { start: 0, end: 6 },
             },
+            span: Span { start: 0, end: 6 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -348,10 +615,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("|>continue"),
                 token_type: TokenType::IterationContinue,
+                span: Span { start: 0, end: 10 },
             },
+            span: Span { start: 0, end: 10 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -366,10 +636,13 @@ mod value_precedence_tests {
             token: Token {
                 value: String::from("|>complete"),
                 token_type: TokenType::IterationComplete,
+                span: Span { start: 0, end: 10 },
             },
+            span: Span { start: 0, end: 10 },
             left: None,
             right: None,
-            parent: None
+            parent: None,
+            children: vec![]
         }]);
         assert_eq!(ast.root, 0);
         assert_eq!(ast.sub_roots, vec![]);
@@ -378,14 +651,13 @@ mod value_precedence_tests {
 
 #[cfg(test)]
 mod dot_access_precedence_tests {
-    use crate::{Lexer, TokenType, Token, Node, Parser, Classification};
     use super::tests::ast_from;
 
     #[test]
     fn decimal_is_above_numbers() {
         let ast = ast_from("3.14");
 
-        let node = ast.nodes.get(0).unwrap();
+        let node = ast.nodes.first().unwrap();
         assert_eq!(node.parent, Some(1));
         assert_eq!(node.left, None);
         assert_eq!(node.right, None);
@@ -407,7 +679,7 @@ mod dot_access_precedence_tests {
     fn access_is_above_identifiers() {
         let ast = ast_from("my_object.my_value");
 
-        let node = ast.nodes.get(0).unwrap();
+        let node = ast.nodes.first().unwrap();
         assert_eq!(node.parent, Some(1));
         assert_eq!(node.left, None);
         assert_eq!(node.right, None);
@@ -429,7 +701,7 @@ mod dot_access_precedence_tests {
     fn access_is_above_decimal() {
         let ast = ast_from("3.14.my_value");
 
-        let node = ast.nodes.get(0).unwrap();
+        let node = ast.nodes.first().unwrap();
         assert_eq!(node.parent, Some(1));
         assert_eq!(node.left, None);
         assert_eq!(node.right, None);
@@ -458,15 +730,15 @@ mod dot_access_precedence_tests {
     }
 }
 
+#[cfg(test)]
 mod unary_precedence_tests {
-    use crate::{Lexer, TokenType, Token, Node, Parser, Classification};
     use super::tests::ast_from;
 
     #[test]
     fn absolute_value() {
         let ast = ast_from("+10");
 
-        let node = ast.nodes.get(0).unwrap();
+        let node = ast.nodes.first().unwrap();
         assert_eq!(node.parent, None);
         assert_eq!(node.left, None);
         assert_eq!(node.right, Some(1));
@@ -481,7 +753,7 @@ mod unary_precedence_tests {
     fn negation() {
         let ast = ast_from("-10");
 
-        let node = ast.nodes.get(0).unwrap();
+        let node = ast.nodes.first().unwrap();
         assert_eq!(node.parent, None);
         assert_eq!(node.left, None);
         assert_eq!(node.right, Some(1));
@@ -496,7 +768,7 @@ mod unary_precedence_tests {
     fn not() {
         let ast = ast_from("!10");
 
-        let node = ast.nodes.get(0).unwrap();
+        let node = ast.nodes.first().unwrap();
         assert_eq!(node.parent, None);
         assert_eq!(node.left, None);
         assert_eq!(node.right, Some(1));
@@ -507,3 +779,585 @@ mod unary_precedence_tests {
         assert_eq!(node.right, None);
     }
 }
+
+#[cfg(test)]
+mod exponent_precedence_tests {
+    use super::tests::ast_from;
+
+    #[test]
+    fn exponent() {
+        let ast = ast_from("2 ** 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn is_right_associative() {
+        // 2 ** 3 ** 4 should bind as 2 ** (3 ** 4), so the first exponent
+        // node is root with the second as its right child
+        let ast = ast_from("2 ** 3 ** 4");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(3));
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, Some(1));
+        assert_eq!(node.left, Some(2));
+        assert_eq!(node.right, Some(4));
+    }
+}
+
+#[cfg(test)]
+mod multiplicative_precedence_tests {
+    use super::tests::ast_from;
+
+    #[test]
+    fn multiplication() {
+        let ast = ast_from("2 * 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn division() {
+        let ast = ast_from("2 / 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn modulus() {
+        let ast = ast_from("2 % 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn binds_looser_than_exponent() {
+        // 2 ** 3 * 4 should bind as (2 ** 3) * 4
+        let ast = ast_from("2 ** 3 * 4");
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(1));
+        assert_eq!(node.right, Some(4));
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, Some(3));
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn chain_of_multiplications_flattens_into_one_node() {
+        let ast = ast_from("2 * 3 * 4");
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, None);
+        assert_eq!(node.right, None);
+        assert_eq!(node.children, vec![0, 2, 4]);
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, Some(3));
+        assert_eq!(node.left, None);
+        assert_eq!(node.right, None);
+
+        for i in [0, 2, 4] {
+            assert_eq!(ast.nodes.get(i).unwrap().parent, Some(3));
+        }
+    }
+
+    #[test]
+    fn multiplications_separated_by_a_looser_operator_do_not_fold_together() {
+        // the two `*` here are unrelated: each is an operand of the `+`
+        // between them, not a four-term chain missing its middle operand
+        let ast = ast_from("2 * 3 + 4 * 5");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, Some(3));
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+        assert_eq!(node.children, Vec::<usize>::new());
+
+        let node = ast.nodes.get(5).unwrap();
+        assert_eq!(node.parent, Some(3));
+        assert_eq!(node.left, Some(4));
+        assert_eq!(node.right, Some(6));
+        assert_eq!(node.children, Vec::<usize>::new());
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(1));
+        assert_eq!(node.right, Some(5));
+        assert_eq!(node.children, Vec::<usize>::new());
+    }
+}
+
+#[cfg(test)]
+mod additive_precedence_tests {
+    use super::tests::ast_from;
+
+    #[test]
+    fn addition() {
+        let ast = ast_from("2 + 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn subtraction() {
+        let ast = ast_from("2 - 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn binds_tighter_than_relational() {
+        // 1 < 2 + 3 should bind as 1 < (2 + 3)
+        let ast = ast_from("1 < 2 + 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(3));
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, Some(1));
+        assert_eq!(node.left, Some(2));
+        assert_eq!(node.right, Some(4));
+    }
+
+    #[test]
+    fn chain_of_additions_flattens_into_one_node() {
+        let ast = ast_from("1 + 2 + 3");
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, None);
+        assert_eq!(node.right, None);
+        assert_eq!(node.children, vec![0, 2, 4]);
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, Some(3));
+        assert_eq!(node.left, None);
+        assert_eq!(node.right, None);
+
+        for i in [0, 2, 4] {
+            assert_eq!(ast.nodes.get(i).unwrap().parent, Some(3));
+        }
+    }
+
+    #[test]
+    fn subtraction_breaks_the_addition_chain() {
+        // 1 + 2 + 3 - 4 + 5 only folds the leading "1 + 2 + 3" run; the
+        // subtraction and the trailing addition stay plain binary nodes
+        let ast = ast_from("1 + 2 + 3 - 4 + 5");
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, Some(5));
+        assert_eq!(node.left, None);
+        assert_eq!(node.right, None);
+        assert_eq!(node.children, vec![0, 2, 4]);
+
+        let node = ast.nodes.get(5).unwrap();
+        assert_eq!(node.parent, Some(7));
+        assert_eq!(node.left, Some(3));
+        assert_eq!(node.right, Some(6));
+        assert_eq!(node.children, Vec::<usize>::new());
+
+        let node = ast.nodes.get(7).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(5));
+        assert_eq!(node.right, Some(8));
+        assert_eq!(node.children, Vec::<usize>::new());
+    }
+}
+
+#[cfg(test)]
+mod relational_precedence_tests {
+    use super::tests::ast_from;
+
+    #[test]
+    fn less_than() {
+        let ast = ast_from("2 < 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn less_than_or_equal() {
+        let ast = ast_from("2 <= 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn greater_than() {
+        let ast = ast_from("2 > 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn greater_than_or_equal() {
+        let ast = ast_from("2 >= 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn binds_tighter_than_equality() {
+        // 1 == 2 < 3 should bind as 1 == (2 < 3)
+        let ast = ast_from("1 == 2 < 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(3));
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, Some(1));
+        assert_eq!(node.left, Some(2));
+        assert_eq!(node.right, Some(4));
+    }
+}
+
+#[cfg(test)]
+mod equality_precedence_tests {
+    use super::tests::ast_from;
+
+    #[test]
+    fn equality() {
+        let ast = ast_from("2 == 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn inequality() {
+        let ast = ast_from("2 != 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn binds_tighter_than_logical_and() {
+        // 1 && 2 == 3 should bind as 1 && (2 == 3)
+        let ast = ast_from("1 && 2 == 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(3));
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, Some(1));
+        assert_eq!(node.left, Some(2));
+        assert_eq!(node.right, Some(4));
+    }
+}
+
+#[cfg(test)]
+mod logical_and_precedence_tests {
+    use super::tests::ast_from;
+
+    #[test]
+    fn and() {
+        let ast = ast_from("1 && 2");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn binds_tighter_than_logical_or() {
+        // 1 || 2 && 3 should bind as 1 || (2 && 3)
+        let ast = ast_from("1 || 2 && 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(3));
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, Some(1));
+        assert_eq!(node.left, Some(2));
+        assert_eq!(node.right, Some(4));
+    }
+
+    #[test]
+    fn chain_of_ands_flattens_into_one_node() {
+        let ast = ast_from("1 && 2 && 3");
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, None);
+        assert_eq!(node.right, None);
+        assert_eq!(node.children, vec![0, 2, 4]);
+    }
+}
+
+#[cfg(test)]
+mod logical_or_precedence_tests {
+    use super::tests::ast_from;
+
+    #[test]
+    fn or() {
+        let ast = ast_from("1 || 2");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+    }
+
+    #[test]
+    fn chain_of_ors_flattens_into_one_node() {
+        let ast = ast_from("1 || 2 || 3");
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, None);
+        assert_eq!(node.right, None);
+        assert_eq!(node.children, vec![0, 2, 4]);
+    }
+}
+
+#[cfg(test)]
+mod iteration_precedence_tests {
+    use crate::Classification;
+    use super::tests::ast_from;
+
+    #[test]
+    fn iteration() {
+        let ast = ast_from("a |> b");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+
+        assert_eq!(ast.root, 1);
+    }
+
+    #[test]
+    fn output_node_becomes_the_iteration_body() {
+        // the output node claims the preceding body expression as its left
+        // child and the emitted expression as its right, and it - not the
+        // bare "body" identifier - becomes the iteration's body (right child)
+        let ast = ast_from("collection |> body |>output expr");
+
+        let iteration = ast.nodes.get(1).unwrap();
+        assert_eq!(iteration.parent, None);
+        assert_eq!(iteration.left, Some(0));
+        assert_eq!(iteration.right, Some(3));
+
+        let output = ast.nodes.get(3).unwrap();
+        assert_eq!(output.classification, Classification::IterationOutput);
+        assert_eq!(output.parent, Some(1));
+        assert_eq!(output.left, Some(2));
+        assert_eq!(output.right, Some(4));
+
+        assert_eq!(ast.root, 1);
+    }
+}
+
+#[cfg(test)]
+mod grouping_precedence_tests {
+    use super::tests::ast_from;
+
+    #[test]
+    fn group_is_its_own_root() {
+        let ast = ast_from("(1 + 2)");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+
+        assert_eq!(ast.root, 1);
+        assert_eq!(ast.sub_roots, vec![1]);
+    }
+
+    #[test]
+    fn group_binds_tighter_than_surrounding_operator() {
+        // (1 + 2) * 3 should bind as (1 + 2) * 3, not 1 + (2 * 3)
+        let ast = ast_from("(1 + 2) * 3");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, Some(3));
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(1));
+        assert_eq!(node.right, Some(4));
+
+        assert_eq!(ast.root, 3);
+        assert_eq!(ast.sub_roots, vec![1]);
+    }
+
+    #[test]
+    fn group_on_the_right() {
+        let ast = ast_from("1 + (2 * 3)");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(3));
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, Some(1));
+        assert_eq!(node.left, Some(2));
+        assert_eq!(node.right, Some(4));
+
+        assert_eq!(ast.root, 1);
+        assert_eq!(ast.sub_roots, vec![3]);
+    }
+
+    #[test]
+    fn group_between_two_operators() {
+        // the two `+` are a genuine chain (the group sits in the middle as
+        // one of its operands), so they fold into one n-ary addition whose
+        // children are [1, group root, 4] in source order
+        let ast = ast_from("1 + (2 * 3) + 4");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, Some(5));
+        assert_eq!(node.left, None);
+        assert_eq!(node.right, None);
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, Some(5));
+        assert_eq!(node.left, Some(2));
+        assert_eq!(node.right, Some(4));
+
+        let node = ast.nodes.get(5).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, None);
+        assert_eq!(node.right, None);
+        assert_eq!(node.children, vec![0, 3, 6]);
+
+        assert_eq!(ast.root, 5);
+        assert_eq!(ast.sub_roots, vec![3]);
+    }
+
+    #[test]
+    fn nested_groups() {
+        // ((1 + 2) * 3) + 4
+        let ast = ast_from("((1 + 2) * 3) + 4");
+
+        let node = ast.nodes.get(1).unwrap();
+        assert_eq!(node.parent, Some(3));
+        assert_eq!(node.left, Some(0));
+        assert_eq!(node.right, Some(2));
+
+        let node = ast.nodes.get(3).unwrap();
+        assert_eq!(node.parent, Some(5));
+        assert_eq!(node.left, Some(1));
+        assert_eq!(node.right, Some(4));
+
+        let node = ast.nodes.get(5).unwrap();
+        assert_eq!(node.parent, None);
+        assert_eq!(node.left, Some(3));
+        assert_eq!(node.right, Some(6));
+
+        assert_eq!(ast.root, 5);
+        assert_eq!(ast.sub_roots, vec![1, 3]);
+    }
+
+    #[test]
+    fn sub_roots_is_accessible_to_callers() {
+        let ast = ast_from("(1 + 2) * (3 + 4)");
+
+        assert_eq!(ast.sub_roots(), &[1, 5]);
+    }
+}
+
+#[cfg(test)]
+mod syntax_error_tests {
+    use crate::{make_ast, Classification, Lexer, Parser, Span, SyntaxError};
+
+    #[test]
+    fn unmatched_closing_group() {
+        let tokens = Lexer::new().lex("1 + 2)").unwrap();
+
+        assert_eq!(Parser::new().make_groups(&tokens).err(), Some(SyntaxError::UnmatchedGroup { span: Span { start: 5, end: 6 } }));
+    }
+
+    #[test]
+    fn unclosed_opening_group() {
+        let tokens = Lexer::new().lex("(1 + 2").unwrap();
+
+        assert_eq!(Parser::new().make_groups(&tokens).err(), Some(SyntaxError::UnmatchedGroup { span: Span { start: 0, end: 1 } }));
+    }
+
+    #[test]
+    fn dangling_operand() {
+        let tokens = Lexer::new().lex("1 +").unwrap();
+        let parse_result = Parser::new().make_groups(&tokens).unwrap();
+
+        assert_eq!(make_ast(parse_result).err(), Some(SyntaxError::DanglingOperand { span: Span { start: 2, end: 3 }, classification: Classification::Addition }));
+    }
+
+    #[test]
+    fn dangling_iteration_body() {
+        let tokens = Lexer::new().lex("collection |>").unwrap();
+        let parse_result = Parser::new().make_groups(&tokens).unwrap();
+
+        assert_eq!(make_ast(parse_result).err(), Some(SyntaxError::DanglingOperand { span: Span { start: 11, end: 13 }, classification: Classification::Iteration }));
+    }
+
+    #[test]
+    fn adjacent_iteration_control_keywords_have_no_root() {
+        // both "|>output" nodes claim each other as their operand, leaving
+        // neither without a parent - this must be a typed error, not a
+        // find_root() panic
+        let tokens = Lexer::new().lex("|>output |>output").unwrap();
+        let parse_result = Parser::new().make_groups(&tokens).unwrap();
+
+        assert_eq!(make_ast(parse_result).err(), Some(SyntaxError::UnresolvedExpression { span: Span { start: 0, end: 17 } }));
+    }
+}
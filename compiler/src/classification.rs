@@ -0,0 +1,28 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    Literal,
+    Decimal,
+    Access,
+    Negation,
+    AbsoluteValue,
+    Not,
+    Exponent,
+    Multiplication,
+    Division,
+    Modulus,
+    Addition,
+    Subtraction,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    Equality,
+    Inequality,
+    And,
+    Or,
+    Iteration,
+    IterationOutput,
+    IterationSkip,
+    IterationContinue,
+    IterationComplete,
+}
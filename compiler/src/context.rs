@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use crate::{EvalError, Value};
+
+type HostFunction = Box<dyn Fn(&[Value]) -> Result<Value, EvalError>>;
+
+/// Named variable bindings and host functions that `evaluate` resolves
+/// `Identifier` nodes and, eventually, function calls against, along with
+/// the values bound to `$` (Input) and `?` (Result).
+#[derive(Default)]
+pub struct Context {
+    values: HashMap<String, Value>,
+    functions: HashMap<String, HostFunction>,
+    input: Option<Value>,
+    result: Option<Value>,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Context::default()
+    }
+
+    pub fn with_value(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn with_function(
+        mut self,
+        name: impl Into<String>,
+        f: impl Fn(&[Value]) -> Result<Value, EvalError> + 'static,
+    ) -> Self {
+        self.functions.insert(name.into(), Box::new(f));
+        self
+    }
+
+    pub fn with_input(mut self, value: impl Into<Value>) -> Self {
+        self.input = Some(value.into());
+        self
+    }
+
+    pub fn with_result(mut self, value: impl Into<Value>) -> Self {
+        self.result = Some(value.into());
+        self
+    }
+
+    pub(crate) fn get_value(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+
+    pub(crate) fn get_function(&self, name: &str) -> Option<&HostFunction> {
+        self.functions.get(name)
+    }
+
+    pub(crate) fn input(&self) -> Option<&Value> {
+        self.input.as_ref()
+    }
+
+    pub(crate) fn result(&self) -> Option<&Value> {
+        self.result.as_ref()
+    }
+}
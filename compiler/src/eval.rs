@@ -0,0 +1,343 @@
+use crate::{Classification, Context, EvalError, Node, TokenType, Value, AST};
+
+type EvalResult = Result<Value, EvalError>;
+
+/// Walks the `AST` rooted at `ast.root`, resolving identifiers, `$`, and `?`
+/// against `ctx` and recursing into each operator's `left`/`right` children
+/// (or its flattened `children` chain, for a folded associative run) to
+/// compute a single `Value`.
+pub fn evaluate(ast: &AST, ctx: &Context) -> EvalResult {
+    eval_node(ast, ast.root, ctx)
+}
+
+fn eval_node(ast: &AST, index: usize, ctx: &Context) -> EvalResult {
+    let node = &ast.nodes[index];
+
+    match node.classification {
+        Classification::Literal => eval_literal(node, ctx),
+        Classification::Decimal => eval_decimal(ast, node),
+        Classification::Negation => eval_numeric_unary(ast, node, ctx, |n| -n, |n| -n),
+        Classification::AbsoluteValue => eval_numeric_unary(ast, node, ctx, |n| n.abs(), |n| n.abs()),
+        Classification::Not => {
+            let operand = eval_child(ast, node.right, ctx)?;
+            Ok(Value::Number(!operand.is_truthy() as i64))
+        }
+        Classification::Exponent => eval_exponent(ast, node, ctx),
+        Classification::Multiplication => eval_checked_fold_arithmetic(ast, node, ctx, |a, b| a.checked_mul(b), |a, b| a * b),
+        Classification::Division => eval_checked_arithmetic(ast, node, ctx, |a, b| a.checked_div(b), |a, b| Some(a / b)),
+        Classification::Modulus => eval_checked_arithmetic(ast, node, ctx, |a, b| a.checked_rem(b), |a, b| Some(a % b)),
+        Classification::Addition => eval_checked_fold_arithmetic(ast, node, ctx, |a, b| a.checked_add(b), |a, b| a + b),
+        Classification::Subtraction => eval_checked_fold_arithmetic(ast, node, ctx, |a, b| a.checked_sub(b), |a, b| a - b),
+        Classification::LessThan => eval_comparison(ast, node, ctx, |a, b| a < b, |a, b| a < b),
+        Classification::LessThanOrEqual => eval_comparison(ast, node, ctx, |a, b| a <= b, |a, b| a <= b),
+        Classification::GreaterThan => eval_comparison(ast, node, ctx, |a, b| a > b, |a, b| a > b),
+        Classification::GreaterThanOrEqual => eval_comparison(ast, node, ctx, |a, b| a >= b, |a, b| a >= b),
+        Classification::Equality => eval_comparison(ast, node, ctx, |a, b| a == b, |a, b| a == b),
+        Classification::Inequality => eval_comparison(ast, node, ctx, |a, b| a != b, |a, b| a != b),
+        Classification::And => eval_logical(ast, node, ctx, |a, b| a && b),
+        Classification::Or => eval_logical(ast, node, ctx, |a, b| a || b),
+        Classification::Access
+        | Classification::Iteration
+        | Classification::IterationOutput
+        | Classification::IterationSkip
+        | Classification::IterationContinue
+        | Classification::IterationComplete => Err(EvalError::Unsupported(node.classification)),
+    }
+}
+
+fn eval_child(ast: &AST, index: Option<usize>, ctx: &Context) -> EvalResult {
+    match index {
+        Some(i) => eval_node(ast, i, ctx),
+        None => Err(EvalError::TypeMismatch { expected: "operand", classification: Classification::Literal }),
+    }
+}
+
+/// Evaluates a node's operands as a flat, source-ordered list: its folded
+/// `children` chain when it has one, or its plain `left`/`right` pair
+/// otherwise.
+fn eval_operands(ast: &AST, node: &Node, ctx: &Context) -> Result<Vec<Value>, EvalError> {
+    if node.children.is_empty() {
+        Ok(vec![eval_child(ast, node.left, ctx)?, eval_child(ast, node.right, ctx)?])
+    } else {
+        node.children.iter().map(|&i| eval_node(ast, i, ctx)).collect()
+    }
+}
+
+fn eval_logical(ast: &AST, node: &Node, ctx: &Context, combine: impl Fn(bool, bool) -> bool) -> EvalResult {
+    let mut operands = eval_operands(ast, node, ctx)?.into_iter();
+    let mut acc = operands.next().expect("operator always has at least one operand").is_truthy();
+
+    for operand in operands {
+        acc = combine(acc, operand.is_truthy());
+    }
+
+    Ok(Value::Number(acc as i64))
+}
+
+fn eval_literal(node: &Node, ctx: &Context) -> EvalResult {
+    match node.token.token_type {
+        TokenType::Number => node.token.value.parse::<i64>()
+            .map(Value::Number)
+            .map_err(|_| EvalError::TypeMismatch { expected: "number", classification: node.classification }),
+        TokenType::Character => node.token.value.chars().next()
+            .map(Value::Character)
+            .ok_or(EvalError::TypeMismatch { expected: "character", classification: node.classification }),
+        TokenType::CharacterList => Ok(Value::CharacterList(node.token.value.clone())),
+        TokenType::UnitLiteral => Ok(Value::Unit),
+        TokenType::SymbolOperator => Ok(Value::Symbol(node.token.value.clone())),
+        TokenType::Input => ctx.input().cloned().ok_or(EvalError::MissingInput),
+        TokenType::Result => ctx.result().cloned().ok_or(EvalError::MissingResult),
+        TokenType::Identifier => {
+            if let Some(value) = ctx.get_value(&node.token.value) {
+                return Ok(value.clone());
+            }
+
+            if let Some(f) = ctx.get_function(&node.token.value) {
+                return f(&[]);
+            }
+
+            Err(EvalError::UndefinedIdentifier(node.token.value.clone()))
+        }
+        _ => Err(EvalError::Unsupported(node.classification)),
+    }
+}
+
+fn eval_decimal(ast: &AST, node: &Node) -> EvalResult {
+    let whole = eval_digits(ast, node.left)?;
+    let fraction = eval_digits(ast, node.right)?;
+
+    format!("{}.{}", whole, fraction).parse::<f64>()
+        .map(Value::Decimal)
+        .map_err(|_| EvalError::TypeMismatch { expected: "decimal", classification: Classification::Decimal })
+}
+
+fn eval_digits(ast: &AST, index: Option<usize>) -> Result<String, EvalError> {
+    match index {
+        Some(i) if ast.nodes[i].token.token_type == TokenType::Number => Ok(ast.nodes[i].token.value.clone()),
+        _ => Err(EvalError::TypeMismatch { expected: "number", classification: Classification::Decimal }),
+    }
+}
+
+fn eval_numeric_unary(
+    ast: &AST,
+    node: &Node,
+    ctx: &Context,
+    on_number: impl Fn(i64) -> i64,
+    on_decimal: impl Fn(f64) -> f64,
+) -> EvalResult {
+    match eval_child(ast, node.right, ctx)? {
+        Value::Number(n) => Ok(Value::Number(on_number(n))),
+        Value::Decimal(n) => Ok(Value::Decimal(on_decimal(n))),
+        _ => Err(EvalError::TypeMismatch { expected: "number or decimal", classification: node.classification }),
+    }
+}
+
+/// Folds the operands left to right; the integer side is `checked_*`
+/// instead of a plain operator, so a chain that would have overflowed (or
+/// wrapped in release builds) surfaces `EvalError::Overflow` instead. The
+/// decimal side is left unchecked since `f64` saturates to infinity rather
+/// than panicking.
+fn eval_checked_fold_arithmetic(
+    ast: &AST,
+    node: &Node,
+    ctx: &Context,
+    on_number: impl Fn(i64, i64) -> Option<i64>,
+    on_decimal: impl Fn(f64, f64) -> f64,
+) -> EvalResult {
+    let mut operands = eval_operands(ast, node, ctx)?.into_iter();
+    let mut acc = operands.next().expect("operator always has at least one operand");
+
+    for operand in operands {
+        acc = match numeric_pair(acc, operand, node.classification)? {
+            NumericPair::Numbers(a, b) => Value::Number(on_number(a, b).ok_or(EvalError::Overflow)?),
+            NumericPair::Decimals(a, b) => Value::Decimal(on_decimal(a, b)),
+        };
+    }
+
+    Ok(acc)
+}
+
+/// `**` isn't foldable like `+`/`*` (it's right-associative, not
+/// associative), so it always has exactly one left/right pair - but it
+/// still goes through `eval_operands` like every other arithmetic op for
+/// consistency. The exponent itself must be non-negative to mean anything
+/// for `i64`, so that's rejected before the cast `checked_pow` needs.
+fn eval_exponent(ast: &AST, node: &Node, ctx: &Context) -> EvalResult {
+    let mut operands = eval_operands(ast, node, ctx)?.into_iter();
+    let mut acc = operands.next().expect("operator always has at least one operand");
+
+    for operand in operands {
+        acc = match numeric_pair(acc, operand, node.classification)? {
+            NumericPair::Numbers(a, b) => {
+                let exponent = u32::try_from(b).map_err(|_| EvalError::NegativeExponent)?;
+                Value::Number(a.checked_pow(exponent).ok_or(EvalError::Overflow)?)
+            }
+            NumericPair::Decimals(a, b) => Value::Decimal(a.powf(b)),
+        };
+    }
+
+    Ok(acc)
+}
+
+fn eval_checked_arithmetic(
+    ast: &AST,
+    node: &Node,
+    ctx: &Context,
+    on_number: impl Fn(i64, i64) -> Option<i64>,
+    on_decimal: impl Fn(f64, f64) -> Option<f64>,
+) -> EvalResult {
+    let left = eval_child(ast, node.left, ctx)?;
+    let right = eval_child(ast, node.right, ctx)?;
+
+    match numeric_pair(left, right, node.classification)? {
+        NumericPair::Numbers(a, b) => on_number(a, b).map(Value::Number).ok_or(EvalError::DivisionByZero),
+        NumericPair::Decimals(a, b) => on_decimal(a, b).map(Value::Decimal).ok_or(EvalError::DivisionByZero),
+    }
+}
+
+fn eval_comparison(
+    ast: &AST,
+    node: &Node,
+    ctx: &Context,
+    on_number: impl Fn(i64, i64) -> bool,
+    on_decimal: impl Fn(f64, f64) -> bool,
+) -> EvalResult {
+    let left = eval_child(ast, node.left, ctx)?;
+    let right = eval_child(ast, node.right, ctx)?;
+
+    let result = match numeric_pair(left, right, node.classification)? {
+        NumericPair::Numbers(a, b) => on_number(a, b),
+        NumericPair::Decimals(a, b) => on_decimal(a, b),
+    };
+
+    Ok(Value::Number(result as i64))
+}
+
+enum NumericPair {
+    Numbers(i64, i64),
+    Decimals(f64, f64),
+}
+
+/// Coerces a pair of operands to a common numeric representation,
+/// promoting both to `f64` if either side is a `Decimal`.
+fn numeric_pair(left: Value, right: Value, classification: Classification) -> Result<NumericPair, EvalError> {
+    match (left, right) {
+        (Value::Number(a), Value::Number(b)) => Ok(NumericPair::Numbers(a, b)),
+        (Value::Decimal(a), Value::Decimal(b)) => Ok(NumericPair::Decimals(a, b)),
+        (Value::Number(a), Value::Decimal(b)) => Ok(NumericPair::Decimals(a as f64, b)),
+        (Value::Decimal(a), Value::Number(b)) => Ok(NumericPair::Decimals(a, b as f64)),
+        _ => Err(EvalError::TypeMismatch { expected: "number or decimal", classification }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::evaluate;
+    use crate::{make_ast, Context, EvalError, Lexer, Parser, Value};
+
+    fn eval_str(s: &str, ctx: &Context) -> Result<Value, EvalError> {
+        let tokens = Lexer::new().lex(s).unwrap();
+        let parse_result = Parser::new().make_groups(&tokens).unwrap();
+        let ast = make_ast(parse_result).unwrap();
+
+        evaluate(&ast, ctx)
+    }
+
+    #[test]
+    fn arithmetic_respects_precedence() {
+        assert_eq!(eval_str("1 + 2 * 3", &Context::new()), Ok(Value::Number(7)));
+        assert_eq!(eval_str("(1 + 2) * 3", &Context::new()), Ok(Value::Number(9)));
+    }
+
+    #[test]
+    fn exponent_is_right_associative() {
+        // 2 ** (3 ** 2), not (2 ** 3) ** 2
+        assert_eq!(eval_str("2 ** 3 ** 2", &Context::new()), Ok(Value::Number(512)));
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn decimal_literal() {
+        assert_eq!(eval_str("3.14", &Context::new()), Ok(Value::Decimal(3.14)));
+    }
+
+    #[test]
+    fn decimal_promotes_mixed_arithmetic() {
+        assert_eq!(eval_str("1 + 2.5", &Context::new()), Ok(Value::Decimal(3.5)));
+    }
+
+    #[test]
+    fn unary_operators() {
+        assert_eq!(eval_str("-10", &Context::new()), Ok(Value::Number(-10)));
+        assert_eq!(eval_str("+-10", &Context::new()), Ok(Value::Number(10)));
+        assert_eq!(eval_str("!0", &Context::new()), Ok(Value::Number(1)));
+    }
+
+    #[test]
+    fn comparisons_and_logical_operators() {
+        assert_eq!(eval_str("1 < 2 && 3 == 3", &Context::new()), Ok(Value::Number(1)));
+        assert_eq!(eval_str("1 > 2 || 3 != 3", &Context::new()), Ok(Value::Number(0)));
+    }
+
+    #[test]
+    fn division_and_modulus() {
+        assert_eq!(eval_str("10 % 3", &Context::new()), Ok(Value::Number(1)));
+        assert_eq!(eval_str("10 / 0", &Context::new()), Err(EvalError::DivisionByZero));
+    }
+
+    #[test]
+    fn integer_overflow_is_a_typed_error() {
+        assert_eq!(eval_str("10 ** 100", &Context::new()), Err(EvalError::Overflow));
+        assert_eq!(eval_str("9999999999 * 9999999999", &Context::new()), Err(EvalError::Overflow));
+        assert_eq!(eval_str("9223372036854775807 + 1", &Context::new()), Err(EvalError::Overflow));
+    }
+
+    #[test]
+    fn negative_exponent_is_a_typed_error() {
+        assert_eq!(eval_str("2 ** -1", &Context::new()), Err(EvalError::NegativeExponent));
+    }
+
+    #[test]
+    fn folded_chains_evaluate_left_to_right() {
+        assert_eq!(eval_str("1 + 2 + 3 + 4", &Context::new()), Ok(Value::Number(10)));
+        assert_eq!(eval_str("2 * 3 * 4", &Context::new()), Ok(Value::Number(24)));
+        assert_eq!(eval_str("1 && 1 && 0", &Context::new()), Ok(Value::Number(0)));
+        assert_eq!(eval_str("0 || 0 || 1", &Context::new()), Ok(Value::Number(1)));
+    }
+
+    #[test]
+    fn same_classification_ops_separated_by_a_looser_operator_do_not_fold_together() {
+        // the two `*` (and the two `&&`) here are unrelated pairs, each
+        // scoped to its own side of the looser operator between them - not
+        // a four-term chain missing its middle operand
+        assert_eq!(eval_str("2 * 3 + 4 * 5", &Context::new()), Ok(Value::Number(26)));
+        assert_eq!(eval_str("1 + 2 < 3 + 4", &Context::new()), Ok(Value::Number(1)));
+        assert_eq!(eval_str("1 && 0 || 1 && 1", &Context::new()), Ok(Value::Number(1)));
+    }
+
+    #[test]
+    fn resolves_identifiers_from_context() {
+        let ctx = Context::new().with_value("x", 8);
+        assert_eq!(eval_str("x + 1", &ctx), Ok(Value::Number(9)));
+    }
+
+    #[test]
+    fn undefined_identifier_is_a_typed_error() {
+        assert_eq!(eval_str("undefined_name", &Context::new()), Err(EvalError::UndefinedIdentifier(String::from("undefined_name"))));
+    }
+
+    #[test]
+    fn zero_arg_function_lookup() {
+        let ctx = Context::new().with_function("f", |_args| Ok(Value::Number(42)));
+        assert_eq!(eval_str("f", &ctx), Ok(Value::Number(42)));
+    }
+
+    #[test]
+    fn input_and_result_bind_from_context() {
+        assert_eq!(eval_str("$", &Context::new()), Err(EvalError::MissingInput));
+        assert_eq!(eval_str("$", &Context::new().with_input(5)), Ok(Value::Number(5)));
+
+        assert_eq!(eval_str("?", &Context::new()), Err(EvalError::MissingResult));
+        assert_eq!(eval_str("?", &Context::new().with_result('a')), Ok(Value::Character('a')));
+    }
+}
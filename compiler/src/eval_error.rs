@@ -0,0 +1,21 @@
+use crate::Classification;
+
+/// A typed evaluation failure, as opposed to a panic, so embedders can
+/// surface a specific reason (and eventually a source span, see `Node`)
+/// rather than just crashing on malformed input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    UndefinedIdentifier(String),
+    MissingInput,
+    MissingResult,
+    TypeMismatch { expected: &'static str, classification: Classification },
+    DivisionByZero,
+    /// An integer arithmetic operation (`+`, `-`, `*`, `**`) would have
+    /// wrapped or panicked rather than produce a mathematically correct
+    /// result.
+    Overflow,
+    /// `**`'s right-hand operand was a negative number, which `i64` doesn't
+    /// have a meaningful exponent for.
+    NegativeExponent,
+    Unsupported(Classification),
+}
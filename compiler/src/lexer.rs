@@ -0,0 +1,254 @@
+use crate::{Span, Token, TokenType};
+use expr_lang_common::Result;
+
+#[derive(Default)]
+pub struct Lexer;
+
+impl Lexer {
+    pub fn new() -> Self {
+        Lexer
+    }
+
+    pub fn lex(&self, s: &str) -> Result<Vec<Token>> {
+        let chars: Vec<char> = s.chars().collect();
+
+        // byte offset of each char, plus a trailing entry for the string's
+        // total byte length, so a token spanning chars[start..end] can be
+        // translated to byte offsets even when end == chars.len()
+        let mut byte_offsets: Vec<usize> = Vec::with_capacity(chars.len() + 1);
+        let mut byte_pos = 0;
+        for c in chars.iter() {
+            byte_offsets.push(byte_pos);
+            byte_pos += c.len_utf8();
+        }
+        byte_offsets.push(byte_pos);
+
+        let span_of = |start: usize, end: usize| Span {
+            start: byte_offsets[start.min(chars.len())],
+            end: byte_offsets[end.min(chars.len())],
+        };
+
+        let mut tokens = vec![];
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+
+            if c.is_whitespace() {
+                i += 1;
+                continue;
+            }
+
+            if c == '(' {
+                let start = i;
+                // empty parens are the unit literal, anything else is a group
+                if chars.get(i + 1) == Some(&')') {
+                    i += 2;
+                    tokens.push(Token { value: String::from("()"), token_type: TokenType::UnitLiteral, span: span_of(start, i) });
+                } else {
+                    i += 1;
+                    tokens.push(Token { value: String::from("("), token_type: TokenType::StartGroup, span: span_of(start, i) });
+                }
+                continue;
+            }
+
+            if c == ')' {
+                let start = i;
+                i += 1;
+                tokens.push(Token { value: String::from(")"), token_type: TokenType::EndGroup, span: span_of(start, i) });
+                continue;
+            }
+
+            if c.is_ascii_digit() {
+                let start = i;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                tokens.push(Token { value: chars[start..i].iter().collect(), token_type: TokenType::Number, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '.' {
+                let start = i;
+                i += 1;
+                tokens.push(Token { value: String::from("."), token_type: TokenType::Dot, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '\'' {
+                let token_start = i;
+                let start = i + 1;
+                let mut end = start;
+                while end < chars.len() && chars[end] != '\'' {
+                    end += 1;
+                }
+                i = end + 1;
+                tokens.push(Token { value: chars[start..end].iter().collect(), token_type: TokenType::Character, span: span_of(token_start, i) });
+                continue;
+            }
+
+            if c == '"' {
+                let token_start = i;
+                let start = i + 1;
+                let mut end = start;
+                while end < chars.len() && chars[end] != '"' {
+                    end += 1;
+                }
+                i = end + 1;
+                tokens.push(Token { value: chars[start..end].iter().collect(), token_type: TokenType::CharacterList, span: span_of(token_start, i) });
+                continue;
+            }
+
+            if c == '$' {
+                let start = i;
+                i += 1;
+                tokens.push(Token { value: String::from("$"), token_type: TokenType::Input, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '?' {
+                let start = i;
+                i += 1;
+                tokens.push(Token { value: String::from("?"), token_type: TokenType::Result, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == ':' {
+                let start = i;
+                i += 1;
+                tokens.push(Token { value: String::from(":"), token_type: TokenType::SymbolOperator, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '|' && chars.get(i + 1) == Some(&'>') {
+                let start = i;
+                i += 2;
+                let word_start = i;
+                while i < chars.len() && chars[i].is_alphabetic() {
+                    i += 1;
+                }
+                let word: String = chars[word_start..i].iter().collect();
+                let (token_type, value) = match word.as_str() {
+                    "output" => (TokenType::IterationOutput, "|>output"),
+                    "skip" => (TokenType::IterationSkip, "|>skip"),
+                    "continue" => (TokenType::IterationContinue, "|>continue"),
+                    "complete" => (TokenType::IterationComplete, "|>complete"),
+                    "" => (TokenType::Iteration, "|>"),
+                    _ => (TokenType::Identifier, "|>"),
+                };
+                tokens.push(Token { value: String::from(value), token_type, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '+' {
+                let start = i;
+                i += 1;
+                tokens.push(Token { value: String::from("+"), token_type: TokenType::Plus, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '-' {
+                let start = i;
+                i += 1;
+                tokens.push(Token { value: String::from("-"), token_type: TokenType::Minus, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '*' {
+                let start = i;
+                if chars.get(i + 1) == Some(&'*') {
+                    i += 2;
+                    tokens.push(Token { value: String::from("**"), token_type: TokenType::Caret, span: span_of(start, i) });
+                } else {
+                    i += 1;
+                    tokens.push(Token { value: String::from("*"), token_type: TokenType::Star, span: span_of(start, i) });
+                }
+                continue;
+            }
+
+            if c == '/' {
+                let start = i;
+                i += 1;
+                tokens.push(Token { value: String::from("/"), token_type: TokenType::Slash, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '%' {
+                let start = i;
+                i += 1;
+                tokens.push(Token { value: String::from("%"), token_type: TokenType::Percent, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '!' {
+                let start = i;
+                if chars.get(i + 1) == Some(&'=') {
+                    i += 2;
+                    tokens.push(Token { value: String::from("!="), token_type: TokenType::Inequality, span: span_of(start, i) });
+                } else {
+                    i += 1;
+                    tokens.push(Token { value: String::from("!"), token_type: TokenType::Not, span: span_of(start, i) });
+                }
+                continue;
+            }
+
+            if c == '<' {
+                let start = i;
+                if chars.get(i + 1) == Some(&'=') {
+                    i += 2;
+                    tokens.push(Token { value: String::from("<="), token_type: TokenType::LessThanOrEqual, span: span_of(start, i) });
+                } else {
+                    i += 1;
+                    tokens.push(Token { value: String::from("<"), token_type: TokenType::LessThan, span: span_of(start, i) });
+                }
+                continue;
+            }
+
+            if c == '>' {
+                let start = i;
+                if chars.get(i + 1) == Some(&'=') {
+                    i += 2;
+                    tokens.push(Token { value: String::from(">="), token_type: TokenType::GreaterThanOrEqual, span: span_of(start, i) });
+                } else {
+                    i += 1;
+                    tokens.push(Token { value: String::from(">"), token_type: TokenType::GreaterThan, span: span_of(start, i) });
+                }
+                continue;
+            }
+
+            if c == '=' && chars.get(i + 1) == Some(&'=') {
+                let start = i;
+                i += 2;
+                tokens.push(Token { value: String::from("=="), token_type: TokenType::Equality, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '&' && chars.get(i + 1) == Some(&'&') {
+                let start = i;
+                i += 2;
+                tokens.push(Token { value: String::from("&&"), token_type: TokenType::And, span: span_of(start, i) });
+                continue;
+            }
+
+            if c == '|' && chars.get(i + 1) == Some(&'|') {
+                let start = i;
+                i += 2;
+                tokens.push(Token { value: String::from("||"), token_type: TokenType::Or, span: span_of(start, i) });
+                continue;
+            }
+
+            if c.is_alphabetic() || c == '_' {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token { value: chars[start..i].iter().collect(), token_type: TokenType::Identifier, span: span_of(start, i) });
+                continue;
+            }
+
+            i += 1;
+        }
+
+        Ok(tokens)
+    }
+}
@@ -0,0 +1,25 @@
+mod token;
+mod classification;
+mod span;
+mod node;
+mod lexer;
+mod syntax_error;
+mod parser;
+mod ast;
+mod value;
+mod eval_error;
+mod context;
+mod eval;
+
+pub use token::{Token, TokenType};
+pub use classification::Classification;
+pub use span::Span;
+pub use node::Node;
+pub use lexer::Lexer;
+pub use syntax_error::SyntaxError;
+pub use parser::{Parser, ParseResult};
+pub use ast::{AST, make_ast};
+pub use value::Value;
+pub use eval_error::EvalError;
+pub use context::Context;
+pub use eval::evaluate;
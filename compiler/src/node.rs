@@ -0,0 +1,15 @@
+use crate::{Classification, Span, Token};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    pub classification: Classification,
+    pub token: Token,
+    pub span: Span,
+    pub left: Option<usize>,
+    pub right: Option<usize>,
+    pub parent: Option<usize>,
+    /// A flattened, source-ordered operand list for an associative operator
+    /// chain (e.g. `a + b + c`), in place of nested `left`/`right` pairs.
+    /// Empty for every node that isn't the root of such a chain.
+    pub children: Vec<usize>,
+}
@@ -0,0 +1,120 @@
+use crate::{Classification, Node, Span, SyntaxError, Token, TokenType};
+
+pub struct ParseResult {
+    pub(crate) nodes: Vec<Node>,
+    pub(crate) sub_expressions: Vec<(usize, usize)>,
+}
+
+#[derive(Default)]
+pub struct Parser;
+
+impl Parser {
+    pub fn new() -> Self {
+        Parser
+    }
+
+    /// Flattens a token stream into a doubly-linked list of `Node`s (via
+    /// `left`/`right` indices) and records the inclusive node-index range of
+    /// every parenthesized group in `sub_expressions`, so `make_ast` can
+    /// resolve each one into its own precedence-ordered subtree before
+    /// splicing the result back into the expression that contains it.
+    pub fn make_groups(&self, tokens: &[Token]) -> Result<ParseResult, SyntaxError> {
+        let mut nodes: Vec<Node> = vec![];
+        let mut sub_expressions = vec![];
+        let mut group_starts: Vec<(usize, Span)> = vec![];
+        let mut expect_operand = true;
+
+        for (i, token) in tokens.iter().enumerate() {
+            match token.token_type {
+                TokenType::StartGroup => {
+                    group_starts.push((nodes.len(), token.span));
+                    expect_operand = true;
+                    continue;
+                }
+                TokenType::EndGroup => {
+                    match group_starts.pop() {
+                        Some((start, _)) => {
+                            if nodes.len() > start {
+                                sub_expressions.push((start, nodes.len() - 1));
+                            }
+                        }
+                        None => return Err(SyntaxError::UnmatchedGroup { span: token.span }),
+                    }
+                    expect_operand = false;
+                    continue;
+                }
+                _ => (),
+            }
+
+            let classification = classify(token, tokens.get(i.wrapping_sub(1)), tokens.get(i + 1), expect_operand);
+            expect_operand = expects_operand_after(&classification);
+
+            let index = nodes.len();
+            if index > 0 {
+                nodes[index - 1].right = Some(index);
+            }
+
+            nodes.push(Node {
+                classification,
+                token: token.clone(),
+                span: token.span,
+                left: if index > 0 { Some(index - 1) } else { None },
+                right: None,
+                parent: None,
+                children: vec![],
+            });
+        }
+
+        if let Some((_, span)) = group_starts.pop() {
+            return Err(SyntaxError::UnmatchedGroup { span });
+        }
+
+        Ok(ParseResult { nodes, sub_expressions })
+    }
+}
+
+fn classify(token: &Token, prev: Option<&Token>, next: Option<&Token>, expect_operand: bool) -> Classification {
+    match token.token_type {
+        TokenType::Dot => {
+            let prev_is_number = prev.map(|t| t.token_type == TokenType::Number).unwrap_or(false);
+            let next_is_number = next.map(|t| t.token_type == TokenType::Number).unwrap_or(false);
+
+            if prev_is_number && next_is_number {
+                Classification::Decimal
+            } else {
+                Classification::Access
+            }
+        }
+        TokenType::Plus if expect_operand => Classification::AbsoluteValue,
+        TokenType::Minus if expect_operand => Classification::Negation,
+        TokenType::Plus => Classification::Addition,
+        TokenType::Minus => Classification::Subtraction,
+        TokenType::Not => Classification::Not,
+        TokenType::Caret => Classification::Exponent,
+        TokenType::Star => Classification::Multiplication,
+        TokenType::Slash => Classification::Division,
+        TokenType::Percent => Classification::Modulus,
+        TokenType::LessThan => Classification::LessThan,
+        TokenType::LessThanOrEqual => Classification::LessThanOrEqual,
+        TokenType::GreaterThan => Classification::GreaterThan,
+        TokenType::GreaterThanOrEqual => Classification::GreaterThanOrEqual,
+        TokenType::Equality => Classification::Equality,
+        TokenType::Inequality => Classification::Inequality,
+        TokenType::And => Classification::And,
+        TokenType::Or => Classification::Or,
+        TokenType::Iteration => Classification::Iteration,
+        TokenType::IterationOutput => Classification::IterationOutput,
+        TokenType::IterationSkip => Classification::IterationSkip,
+        TokenType::IterationContinue => Classification::IterationContinue,
+        TokenType::IterationComplete => Classification::IterationComplete,
+        _ => Classification::Literal,
+    }
+}
+
+fn expects_operand_after(classification: &Classification) -> bool {
+    !matches!(classification, Classification::Literal
+        | Classification::IterationOutput
+        | Classification::IterationSkip
+        | Classification::IterationContinue
+        | Classification::IterationComplete)
+}
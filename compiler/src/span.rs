@@ -0,0 +1,8 @@
+/// A byte-offset range into the original source string, identifying exactly
+/// where a `Token` or `Node` came from so embedders can render caret-pointed
+/// diagnostics instead of a bare error message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
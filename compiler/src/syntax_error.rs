@@ -0,0 +1,17 @@
+use crate::{Classification, Span};
+
+/// A structured parse/AST failure referencing the source span that caused
+/// it, in place of the panics that unwrapping malformed input would produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxError {
+    /// A `)` with no matching `(`, or a `(` that is never closed.
+    UnmatchedGroup { span: Span },
+    /// An operator is missing an operand it requires after precedence
+    /// resolution, e.g. a trailing `+` with nothing to its right.
+    DanglingOperand { span: Span, classification: Classification },
+    /// Precedence resolution left every node in an expression (or
+    /// sub-expression) claimed as someone else's operand, so no single node
+    /// is left to serve as its root - e.g. two adjacent iteration-control
+    /// keywords (`|>output |>output`) each claiming the other.
+    UnresolvedExpression { span: Span },
+}
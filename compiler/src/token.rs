@@ -0,0 +1,43 @@
+use crate::Span;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenType {
+    Number,
+    Character,
+    CharacterList,
+    Identifier,
+    SymbolOperator,
+    UnitLiteral,
+    Input,
+    Result,
+    StartGroup,
+    EndGroup,
+    Dot,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    Equality,
+    Inequality,
+    And,
+    Or,
+    Not,
+    Iteration,
+    IterationOutput,
+    IterationSkip,
+    IterationContinue,
+    IterationComplete,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub value: String,
+    pub token_type: TokenType,
+    pub span: Span,
+}
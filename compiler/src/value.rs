@@ -0,0 +1,62 @@
+/// A runtime value produced by evaluating an `AST`, covering the literal
+/// kinds the lexer tokenizes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(i64),
+    Decimal(f64),
+    Character(char),
+    CharacterList(String),
+    Unit,
+    Symbol(String),
+}
+
+impl Value {
+    /// Numbers, characters, and non-empty character lists are truthy; `0`,
+    /// `Unit`, and empty character lists are not.
+    pub(crate) fn is_truthy(&self) -> bool {
+        match self {
+            Value::Number(n) => *n != 0,
+            Value::Decimal(n) => *n != 0.0,
+            Value::Character(_) => true,
+            Value::CharacterList(s) => !s.is_empty(),
+            Value::Unit => false,
+            Value::Symbol(_) => true,
+        }
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Number(value)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Decimal(value)
+    }
+}
+
+impl From<char> for Value {
+    fn from(value: char) -> Self {
+        Value::Character(value)
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::CharacterList(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::CharacterList(String::from(value))
+    }
+}
+
+impl From<()> for Value {
+    fn from(_: ()) -> Self {
+        Value::Unit
+    }
+}